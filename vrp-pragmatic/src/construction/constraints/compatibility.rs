@@ -0,0 +1,123 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/constraints/compatibility_test.rs"]
+mod compatibility_test;
+
+use std::collections::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::Actor;
+
+/// A key under which a job's compatibility value is stored in its dimensions. The problem reader
+/// is expected to set this from the job's `"compatibility"` property, the same way it sets
+/// `GroupModule`'s `"group"` dimension from the job's `"group"` property.
+pub const COMPATIBILITY_KEY: &str = "compatibility";
+
+/// A module which ensures that jobs tagged with mutually exclusive compatibility values
+/// (e.g. "food" and "chemicals") never end up on the same route. This is the inverse of
+/// `GroupModule`'s "same group must use the same vehicle" rule.
+///
+/// Like `GroupModule`, this module only takes effect once it is registered on the constraint
+/// pipeline alongside the other hard route constraints.
+pub struct CompatibilityModule {
+    constraints: Vec<Box<dyn HardRouteConstraint + Send + Sync>>,
+    keys: Vec<i32>,
+    state_key: i32,
+}
+
+impl CompatibilityModule {
+    /// Creates a new instance of `CompatibilityModule`.
+    pub fn new(code: i32, state_key: i32) -> Self {
+        Self {
+            constraints: vec![Box::new(CompatibilityHardRouteConstraint { code, state_key })],
+            keys: vec![state_key],
+            state_key,
+        }
+    }
+}
+
+impl ConstraintModule for CompatibilityModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if let Some(compatibility) = get_compatibility(job) {
+            let actor = solution_ctx.routes.get(route_index).unwrap().route.actor.clone();
+            let mut state = get_actor_compatibility(solution_ctx, self.state_key).cloned().unwrap_or_default();
+
+            state.entry(actor).or_insert_with(HashSet::new).insert(compatibility);
+
+            solution_ctx.state.insert(self.state_key, Arc::new(state));
+        }
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        let state = ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| {
+                let compatibilities = route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter_map(|job| get_compatibility(&job))
+                    .collect::<HashSet<_>>();
+
+                if compatibilities.is_empty() {
+                    None
+                } else {
+                    Some((route_ctx.route.actor.clone(), compatibilities))
+                }
+            })
+            .collect::<HashMap<_, _>>();
+
+        ctx.state.insert(self.state_key, Arc::new(state));
+    }
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<Box<dyn HardRouteConstraint + Send + Sync>> {
+        self.constraints.iter()
+    }
+}
+
+/// Checks that a job's compatibility value, if any, does not conflict with the compatibility
+/// values already present on the route it is being inserted into.
+struct CompatibilityHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+}
+
+impl HardRouteConstraint for CompatibilityHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        get_compatibility(job).and_then(|compatibility| {
+            get_actor_compatibility(solution_ctx, self.state_key)
+                .and_then(|state| state.get(&route_ctx.route.actor))
+                .filter(|compatibilities| !compatibilities.contains(&compatibility))
+                .map(|_| RouteConstraintViolation { code: self.code })
+        })
+    }
+}
+
+fn get_compatibility(job: &Job) -> Option<String> {
+    job.dimens().get_value::<String>(COMPATIBILITY_KEY).cloned()
+}
+
+fn get_actor_compatibility(
+    solution_ctx: &SolutionContext,
+    state_key: i32,
+) -> Option<&HashMap<Arc<Actor>, HashSet<String>>> {
+    solution_ctx.state.get(&state_key).and_then(|s| s.downcast_ref::<HashMap<Arc<Actor>, HashSet<String>>>())
+}
@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/jobs_test.rs"]
+mod jobs_test;
+
 use super::*;
 use crate::extensions::MultiDimensionalCapacity;
 
@@ -91,7 +95,12 @@ fn check_e1003_time_window_correctness(ctx: &ValidationContext) -> Result<(), Va
 
     let ids = ctx
         .jobs()
-        .filter(|job| has_invalid_tws(&job.pickups) || has_invalid_tws(&job.deliveries))
+        .filter(|job| {
+            has_invalid_tws(&job.pickups)
+                || has_invalid_tws(&job.deliveries)
+                || has_invalid_tws(&job.services)
+                || has_invalid_tws(&job.replacements)
+        })
         .map(|job| job.id.clone())
         .collect::<Vec<_>>();
 
@@ -106,6 +115,113 @@ fn check_e1003_time_window_correctness(ctx: &ValidationContext) -> Result<(), Va
     }
 }
 
+/// Checks that total demand of all jobs does not exceed the maximum capacity the fleet can offer.
+fn check_e1004_capacity_feasibility(ctx: &ValidationContext) -> Result<(), ValidationError> {
+    let get_demand = |tasks: &Option<Vec<JobTask>>| {
+        if let Some(tasks) = tasks {
+            tasks
+                .iter()
+                .map(|task| {
+                    task.demand.clone().map_or_else(
+                        || MultiDimensionalCapacity::default(),
+                        |demand| MultiDimensionalCapacity::new(demand),
+                    )
+                })
+                .sum()
+        } else {
+            MultiDimensionalCapacity::default()
+        }
+    };
+
+    let total_demand = ctx
+        .jobs()
+        .map(|job| {
+            // a job's pickups and deliveries carry the same cargo through the vehicle (enforced by
+            // E1002), so only one side is counted; replacements are a distinct task type and always add up
+            let pickup_or_delivery_demand =
+                if job.deliveries.is_some() { get_demand(&job.deliveries) } else { get_demand(&job.pickups) };
+
+            pickup_or_delivery_demand + get_demand(&job.replacements)
+        })
+        .fold(MultiDimensionalCapacity::default(), |acc, demand| acc + demand);
+
+    let total_capacity = ctx
+        .fleet()
+        .vehicles
+        .iter()
+        .map(|vehicle| MultiDimensionalCapacity::new(vehicle.capacity.clone()) * vehicle.vehicle_ids.len() as i32)
+        .fold(MultiDimensionalCapacity::default(), |acc, capacity| acc + capacity);
+
+    // compare dimension by dimension rather than zip, so a fleet whose capacity has fewer dimensions
+    // than the demand (or a fleet with no vehicles) isn't silently treated as having unlimited capacity in the rest
+    let dimensions_count = total_demand.capacity.len().max(total_capacity.capacity.len());
+    let dimensions = (0..dimensions_count)
+        .filter(|&idx| {
+            let demand = total_demand.capacity.get(idx).copied().unwrap_or(0);
+            let capacity = total_capacity.capacity.get(idx).copied().unwrap_or(0);
+            demand > capacity
+        })
+        .map(|idx| idx.to_string())
+        .collect::<Vec<_>>();
+
+    if dimensions.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::new(
+            "E1004".to_string(),
+            format!("total jobs demand exceeds fleet capacity in dimensions: {}", dimensions.join(", ")),
+            "add more vehicles or reduce total demand".to_string(),
+        ))
+    }
+}
+
+/// Checks that, for jobs with both pickups and deliveries, there is at least one pickup/delivery
+/// time window pairing for which the pickup can happen before the delivery.
+fn check_e1005_correct_pickup_delivery_time_window_order(ctx: &ValidationContext) -> Result<(), ValidationError> {
+    let get_times = |tasks: &Option<Vec<JobTask>>| {
+        tasks.as_ref().map_or_else(Vec::new, |tasks| {
+            tasks
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.times.as_ref())
+                .flatten()
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.pickups.is_some() && job.deliveries.is_some())
+        .filter(|job| {
+            let pickup_tws = get_times(&job.pickups);
+            let delivery_tws = get_times(&job.deliveries);
+
+            !pickup_tws.is_empty()
+                && !delivery_tws.is_empty()
+                && pickup_tws.iter().all(|pickup_tw| {
+                    delivery_tws.iter().all(|delivery_tw| {
+                        match (pickup_tw.get(0), delivery_tw.get(1)) {
+                            (Some(pickup_start), Some(delivery_end)) => delivery_end < pickup_start,
+                            _ => false,
+                        }
+                    })
+                })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError::new(
+            "E1005".to_string(),
+            format!("pickup and delivery time windows are mutually exclusive for jobs: {}", ids.join(", ")),
+            "change pickup/delivery time windows so that the pickup can happen before the delivery".to_string(),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<ValidationError>> {
     let errors = check_e1000_no_jobs_with_duplicate_ids(ctx)
@@ -115,6 +231,8 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<ValidationError>
         .chain(check_e1001_correct_job_types_demand(ctx).err().iter().cloned())
         .chain(check_e1002_multiple_pickups_deliveries_demand(ctx).err().iter().cloned())
         .chain(check_e1003_time_window_correctness(ctx).err().iter().cloned())
+        .chain(check_e1004_capacity_feasibility(ctx).err().iter().cloned())
+        .chain(check_e1005_correct_pickup_delivery_time_window_order(ctx).err().iter().cloned())
         .collect::<Vec<_>>();
 
     if errors.is_empty() {
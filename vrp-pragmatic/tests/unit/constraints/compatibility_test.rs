@@ -0,0 +1,204 @@
+use super::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::{Fleet, Single};
+
+const VIOLATION_CODE: i32 = 1;
+const STATE_KEY: i32 = 2;
+
+fn create_test_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1")), Arc::new(test_vehicle("v2"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_test_single(compatibility: Option<&str>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    if let Some(compatibility) = compatibility {
+        single.dimens.set_value("compatibility", compatibility.to_string())
+    }
+
+    Arc::new(single)
+}
+
+fn create_test_solution_context(
+    fleet: &Fleet,
+    routes: Vec<(&str, Vec<Option<&str>>)>,
+    actor_compatibility: Option<Vec<(&str, Vec<&str>)>>,
+) -> SolutionContext {
+    let state: HashMap<_, StateValue> = if let Some(actor_compatibility) = actor_compatibility {
+        let mut state: HashMap<_, StateValue> = HashMap::default();
+        state.insert(
+            STATE_KEY,
+            Arc::new(
+                actor_compatibility
+                    .into_iter()
+                    .map(|(vehicle, values)| {
+                        (get_actor(fleet, vehicle), values.into_iter().map(|value| value.to_string()).collect())
+                    })
+                    .collect::<HashMap<_, HashSet<_>>>(),
+            ),
+        );
+        state
+    } else {
+        HashMap::default()
+    };
+
+    SolutionContext {
+        routes: routes
+            .into_iter()
+            .map(|(vehicle, values)| {
+                RouteContext::new_with_state(
+                    Arc::new(create_route_with_activities(
+                        &fleet,
+                        vehicle,
+                        values
+                            .into_iter()
+                            .map(|value| create_activity_with_job_at_location(create_test_single(value), 1))
+                            .collect(),
+                    )),
+                    Arc::new(RouteState::default()),
+                )
+            })
+            .collect(),
+        state,
+        ..create_solution_context_for_fleet(fleet)
+    }
+}
+
+fn get_actor(fleet: &Fleet, vehicle: &str) -> Arc<Actor> {
+    fleet.actors.iter().find(|actor| actor.vehicle.dimens.get_id().unwrap() == vehicle).unwrap().clone()
+}
+
+fn compare_actor_compatibility(
+    fleet: &Fleet,
+    original: &HashMap<Arc<Actor>, HashSet<String>>,
+    expected: Vec<(&str, Vec<&str>)>,
+) {
+    let test = expected
+        .iter()
+        .map(|(vehicle, values)| {
+            (get_actor(fleet, vehicle), values.iter().map(|value| value.to_string()).collect())
+        })
+        .collect::<HashMap<_, HashSet<_>>>();
+
+    assert_eq!(original.len(), test.len());
+    assert!(original.keys().all(|k| test[k] == original[k]));
+}
+
+#[test]
+fn can_build_expected_module() {
+    let module = CompatibilityModule::new(VIOLATION_CODE, STATE_KEY);
+
+    assert_eq!(module.state_keys().cloned().collect::<Vec<_>>(), vec![STATE_KEY]);
+    assert_eq!(module.get_constraints().count(), 1);
+}
+
+parameterized_test! {can_accept_insertion, (routes, job_compatibility, actor_compatibility, expected), {
+    can_accept_insertion_impl(routes, job_compatibility, actor_compatibility, expected);
+}}
+
+can_accept_insertion! {
+    case_01: (vec![("v1", vec![None])], Some("food"), None, vec![("v1", vec!["food"])]),
+    case_02: (
+        vec![("v1", vec![None])],
+        Some("food"),
+        Some(vec![("v2", vec!["chemicals"])]),
+        vec![("v1", vec!["food"]), ("v2", vec!["chemicals"])]
+    ),
+}
+
+fn can_accept_insertion_impl(
+    routes: Vec<(&str, Vec<Option<&str>>)>,
+    job_compatibility: Option<&str>,
+    actor_compatibility: Option<Vec<(&str, Vec<&str>)>>,
+    expected: Vec<(&str, Vec<&str>)>,
+) {
+    let fleet = create_test_fleet();
+    let module = CompatibilityModule::new(VIOLATION_CODE, STATE_KEY);
+    let mut solution = create_test_solution_context(&fleet, routes, actor_compatibility);
+    let job = Job::Single(create_test_single(job_compatibility));
+
+    module.accept_insertion(&mut solution, 0, &job);
+
+    compare_actor_compatibility(&fleet, get_actor_compatibility(&mut solution, STATE_KEY).unwrap(), expected);
+}
+
+parameterized_test! {can_accept_solution_state, (routes, actor_compatibility, expected), {
+    can_accept_solution_state_impl(routes, actor_compatibility, expected);
+}}
+
+can_accept_solution_state! {
+    case_01: (vec![("v1", vec![Some("food")])], None, vec![("v1", vec!["food"])]),
+    case_02: (
+        vec![("v1", vec![Some("food")]), ("v2", vec![Some("chemicals")])],
+        None,
+        vec![("v1", vec!["food"]), ("v2", vec!["chemicals"])]
+    ),
+    case_03: (vec![("v1", vec![Some("food"), Some("chemicals")])], None, vec![("v1", vec!["food", "chemicals"])]),
+    case_04: (vec![("v1", vec![Some("food")])], Some(vec![("v2", vec!["chemicals"])]), vec![("v1", vec!["food"])]),
+    case_05: (vec![("v1", vec![None])], Some(vec![("v1", vec!["food"])]), vec![]),
+}
+
+fn can_accept_solution_state_impl(
+    routes: Vec<(&str, Vec<Option<&str>>)>,
+    actor_compatibility: Option<Vec<(&str, Vec<&str>)>>,
+    expected: Vec<(&str, Vec<&str>)>,
+) {
+    let fleet = create_test_fleet();
+    let module = CompatibilityModule::new(VIOLATION_CODE, STATE_KEY);
+    let mut solution = create_test_solution_context(&fleet, routes, actor_compatibility);
+
+    module.accept_solution_state(&mut solution);
+
+    compare_actor_compatibility(&fleet, get_actor_compatibility(&mut solution, STATE_KEY).unwrap(), expected);
+}
+
+parameterized_test! {can_evaluate_job, (routes, route_idx, job_compatibility, actor_compatibility, expected), {
+    can_evaluate_job_impl(routes, route_idx, job_compatibility, actor_compatibility, expected);
+}}
+
+can_evaluate_job! {
+    case_01: (
+        vec![("v1", vec![]), ("v2", vec![])],
+        1,
+        Some("food"),
+        Some(vec![("v2", vec!["chemicals"])]),
+        Some(VIOLATION_CODE)
+    ),
+    case_02: (vec![("v1", vec![]), ("v2", vec![])], 1, None, Some(vec![("v2", vec!["chemicals"])]), None),
+    case_03: (vec![("v1", vec![]), ("v2", vec![])], 0, Some("food"), Some(vec![("v2", vec!["chemicals"])]), None),
+    case_04: (vec![("v1", vec![])], 0, Some("food"), None, None),
+    case_05: (
+        vec![("v1", vec![]), ("v2", vec![])],
+        1,
+        Some("chemicals"),
+        Some(vec![("v2", vec!["chemicals"])]),
+        None
+    ),
+}
+
+fn can_evaluate_job_impl(
+    routes: Vec<(&str, Vec<Option<&str>>)>,
+    route_idx: usize,
+    job_compatibility: Option<&str>,
+    actor_compatibility: Option<Vec<(&str, Vec<&str>)>>,
+    expected: Option<i32>,
+) {
+    let fleet = create_test_fleet();
+    let solution_ctx = create_test_solution_context(&fleet, routes, actor_compatibility);
+    let route_ctx = solution_ctx.routes.get(route_idx).unwrap();
+    let job = Job::Single(create_test_single(job_compatibility));
+
+    let result = CompatibilityHardRouteConstraint { code: VIOLATION_CODE, state_key: STATE_KEY }.evaluate_job(
+        &solution_ctx,
+        route_ctx,
+        &job,
+    );
+
+    assert_eq!(result, expected.map(|code| RouteConstraintViolation { code }));
+}
@@ -0,0 +1,130 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_test_job_task(demand: Option<Vec<i32>>, times: Option<Vec<Vec<String>>>) -> JobTask {
+    let place = JobPlace { times, ..create_job_place(DEFAULT_JOB_LOCATION) };
+    JobTask { places: vec![place], demand, ..JobTask::default() }
+}
+
+fn times(start: &str, end: &str) -> Vec<Vec<String>> {
+    vec![vec![start.to_string(), end.to_string()]]
+}
+
+fn two_windows(first: (&str, &str), second: (&str, &str)) -> Vec<Vec<String>> {
+    vec![vec![first.0.to_string(), first.1.to_string()], vec![second.0.to_string(), second.1.to_string()]]
+}
+
+parameterized_test! {can_detect_e1004_capacity_feasibility, (capacity, pickup, delivery, replace, expected), {
+    can_detect_e1004_capacity_feasibility_impl(capacity, pickup, delivery, replace, expected);
+}}
+
+can_detect_e1004_capacity_feasibility! {
+    case_01: (vec![vec![5]], Some(vec![5]), Some(vec![5]), None, None),
+    case_02: (vec![vec![5]], Some(vec![10]), Some(vec![10]), None, Some(ValidationError::new(
+        "E1004".to_string(),
+        "total jobs demand exceeds fleet capacity in dimensions: 0".to_string(),
+        "add more vehicles or reduce total demand".to_string(),
+    ))),
+    case_03: (vec![vec![5]], None, Some(vec![3]), None, None),
+    case_04: (vec![], Some(vec![3]), Some(vec![3]), None, Some(ValidationError::new(
+        "E1004".to_string(),
+        "total jobs demand exceeds fleet capacity in dimensions: 0".to_string(),
+        "add more vehicles or reduce total demand".to_string(),
+    ))),
+    case_05: (vec![vec![5]], None, None, Some(vec![10]), Some(ValidationError::new(
+        "E1004".to_string(),
+        "total jobs demand exceeds fleet capacity in dimensions: 0".to_string(),
+        "add more vehicles or reduce total demand".to_string(),
+    ))),
+}
+
+fn can_detect_e1004_capacity_feasibility_impl(
+    capacity: Vec<Vec<i32>>,
+    pickup: Option<Vec<i32>>,
+    delivery: Option<Vec<i32>>,
+    replace: Option<Vec<i32>>,
+    expected: Option<ValidationError>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                id: "job1".to_string(),
+                pickups: pickup.map(|demand| vec![create_test_job_task(Some(demand), None)]),
+                deliveries: delivery.map(|demand| vec![create_test_job_task(Some(demand), None)]),
+                replacements: replace.map(|demand| vec![create_test_job_task(Some(demand), None)]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: capacity
+                .into_iter()
+                .map(|capacity| VehicleType { capacity, ..create_default_vehicle_type() })
+                .collect(),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1004_capacity_feasibility(&ctx).err();
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_detect_e1005_pickup_delivery_time_window_order, (pickup, delivery, expected), {
+    can_detect_e1005_pickup_delivery_time_window_order_impl(pickup, delivery, expected);
+}}
+
+can_detect_e1005_pickup_delivery_time_window_order! {
+    case_01: (
+        times("2020-01-01T10:00:00Z", "2020-01-01T11:00:00Z"),
+        times("2020-01-01T12:00:00Z", "2020-01-01T13:00:00Z"),
+        None
+    ),
+    case_02: (
+        times("2020-01-01T12:00:00Z", "2020-01-01T13:00:00Z"),
+        times("2020-01-01T10:00:00Z", "2020-01-01T11:00:00Z"),
+        Some(ValidationError::new(
+            "E1005".to_string(),
+            "pickup and delivery time windows are mutually exclusive for jobs: job1".to_string(),
+            "change pickup/delivery time windows so that the pickup can happen before the delivery".to_string(),
+        ))
+    ),
+    case_03: (
+        two_windows(
+            ("2020-01-01T10:00:00Z", "2020-01-01T11:00:00Z"),
+            ("2020-01-01T13:00:00Z", "2020-01-01T14:00:00Z"),
+        ),
+        two_windows(
+            ("2020-01-01T09:00:00Z", "2020-01-01T09:30:00Z"),
+            ("2020-01-01T12:00:00Z", "2020-01-01T18:00:00Z"),
+        ),
+        None
+    ),
+}
+
+fn can_detect_e1005_pickup_delivery_time_window_order_impl(
+    pickup_times: Vec<Vec<String>>,
+    delivery_times: Vec<Vec<String>>,
+    expected: Option<ValidationError>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                id: "job1".to_string(),
+                pickups: Some(vec![create_test_job_task(Some(vec![1]), Some(pickup_times))]),
+                deliveries: Some(vec![create_test_job_task(Some(vec![1]), Some(delivery_times))]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_e1005_correct_pickup_delivery_time_window_order(&ctx).err();
+
+    assert_eq!(result, expected);
+}